@@ -1,5 +1,318 @@
-use tauri::{Manager, PhysicalPosition, PhysicalSize, Position};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{
+    AppHandle, Emitter, Manager, PhysicalPosition, PhysicalSize, Position, WebviewWindow,
+    WindowEvent,
+};
 use tauri_plugin_autostart::MacosLauncher;
+use tauri_plugin_global_shortcut::GlobalShortcutExt;
+
+/// Default summon/dismiss chord. `Cmd+Space` on macOS (the plugin maps
+/// `Cmd` to the platform super key) and `Ctrl+Space` elsewhere.
+fn default_shortcut() -> String {
+    if cfg!(target_os = "macos") {
+        "Cmd+Space".to_string()
+    } else {
+        "Ctrl+Space".to_string()
+    }
+}
+
+/// One of the nine screen positions the widget can dock to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum Anchor {
+    TopLeft,
+    TopCenter,
+    TopRight,
+    CenterLeft,
+    Center,
+    CenterRight,
+    BottomLeft,
+    BottomCenter,
+    BottomRight,
+}
+
+/// Persisted widget preferences. Kept in a small JSON file next to the
+/// window-state plugin's store so choices survive restarts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+struct Config {
+    /// Global chord that toggles the launcher, in the
+    /// `tauri_plugin_global_shortcut` textual form (e.g. `Cmd+Space`).
+    shortcut: String,
+    /// Hide the launcher from the Dock/taskbar. When `true` the app runs
+    /// as a macOS accessory and skips the Windows taskbar.
+    background_mode: bool,
+    /// Screen corner/edge the widget docks to.
+    anchor: Anchor,
+    /// Gap in physical pixels between the widget and the screen edge.
+    margin: i32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            shortcut: default_shortcut(),
+            background_mode: true,
+            anchor: Anchor::BottomRight,
+            margin: 14,
+        }
+    }
+}
+
+fn config_path(app: &AppHandle) -> Option<PathBuf> {
+    app.path()
+        .app_config_dir()
+        .ok()
+        .map(|dir| dir.join("launcher.json"))
+}
+
+fn load_config(app: &AppHandle) -> Config {
+    let Some(path) = config_path(app) else {
+        return Config::default();
+    };
+    match fs::read_to_string(&path) {
+        Ok(raw) => serde_json::from_str(&raw).unwrap_or_default(),
+        Err(_) => Config::default(),
+    }
+}
+
+fn save_config(app: &AppHandle, config: &Config) {
+    let Some(path) = config_path(app) else {
+        return;
+    };
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    if let Ok(raw) = serde_json::to_string_pretty(config) {
+        let _ = fs::write(path, raw);
+    }
+}
+
+/// Pin the window to `anchor` on the monitor it currently sits on,
+/// leaving `margin` physical pixels from the edges. The position is only
+/// written when it actually changes, so the same call is safe to fire
+/// from `Moved`/`ScaleFactorChanged` handlers without looping.
+fn position_widget(window: &WebviewWindow, anchor: Anchor, margin: i32) {
+    if let Ok(Some(monitor)) = window.current_monitor() {
+        let monitor_pos = monitor.position();
+        let monitor_size = monitor.size();
+        let window_size = window.outer_size().unwrap_or(PhysicalSize::new(210, 56));
+
+        let max_x = monitor_size.width as i32 - window_size.width as i32;
+        let max_y = monitor_size.height as i32 - window_size.height as i32;
+
+        let (left, center_x, right) = (margin, max_x / 2, max_x - margin);
+        let (top, center_y, bottom) = (margin, max_y / 2, max_y - margin);
+
+        let (dx, dy) = match anchor {
+            Anchor::TopLeft => (left, top),
+            Anchor::TopCenter => (center_x, top),
+            Anchor::TopRight => (right, top),
+            Anchor::CenterLeft => (left, center_y),
+            Anchor::Center => (center_x, center_y),
+            Anchor::CenterRight => (right, center_y),
+            Anchor::BottomLeft => (left, bottom),
+            Anchor::BottomCenter => (center_x, bottom),
+            Anchor::BottomRight => (right, bottom),
+        };
+
+        let desired = PhysicalPosition::new(monitor_pos.x + dx, monitor_pos.y + dy);
+        if window.outer_position().map(|p| p != desired).unwrap_or(true) {
+            let _ = window.set_position(Position::Physical(desired));
+        }
+    }
+}
+
+/// Reposition the widget using the anchor and margin from the live config.
+fn reposition(window: &WebviewWindow) {
+    let app = window.app_handle();
+    let (anchor, margin) = {
+        let config = app.state::<Mutex<Config>>();
+        let config = config.lock().unwrap();
+        (config.anchor, config.margin)
+    };
+    position_widget(window, anchor, margin);
+}
+
+/// Raise the window to a floating panel on macOS so it stays above
+/// fullscreen apps and follows the user across every Space. Passing
+/// `false` restores the ordinary window level and collection behavior.
+#[cfg(target_os = "macos")]
+fn apply_panel_mode(window: &WebviewWindow, enabled: bool) {
+    use cocoa::appkit::NSWindowCollectionBehavior;
+    use cocoa::base::id;
+    use objc::{msg_send, sel, sel_impl};
+
+    let Ok(ns_window) = window.ns_window() else {
+        return;
+    };
+    let ns_window = ns_window as id;
+
+    unsafe {
+        // `NSMainMenuWindowLevel` is 24; one above keeps us over the menu
+        // bar and any fullscreen window. 0 is `NSNormalWindowLevel`.
+        let level: i64 = if enabled { 25 } else { 0 };
+        let _: () = msg_send![ns_window, setLevel: level];
+
+        let behavior = if enabled {
+            NSWindowCollectionBehavior::NSWindowCollectionBehaviorCanJoinAllSpaces
+                | NSWindowCollectionBehavior::NSWindowCollectionBehaviorFullScreenAuxiliary
+        } else {
+            NSWindowCollectionBehavior::NSWindowCollectionBehaviorDefault
+        };
+        let _: () = msg_send![ns_window, setCollectionBehavior: behavior];
+    }
+}
+
+/// No-op on platforms without an NSWindow so shared code keeps compiling.
+#[cfg(not(target_os = "macos"))]
+fn apply_panel_mode(_window: &WebviewWindow, _enabled: bool) {}
+
+/// Toggle floating-panel behavior from the frontend.
+#[tauri::command]
+fn set_panel_mode(window: WebviewWindow, enabled: bool) {
+    apply_panel_mode(&window, enabled);
+}
+
+/// Keep the launcher out of the Dock/taskbar. On macOS this flips the
+/// activation policy to `Accessory` (no Dock icon, never steals focus);
+/// on Windows it sets `skip_taskbar`. Elsewhere it is a no-op.
+fn apply_background_mode(app: &AppHandle, window: &WebviewWindow, enabled: bool) {
+    #[cfg(target_os = "macos")]
+    {
+        let _ = window;
+        let policy = if enabled {
+            tauri::ActivationPolicy::Accessory
+        } else {
+            tauri::ActivationPolicy::Regular
+        };
+        let _ = app.set_activation_policy(policy);
+    }
+    #[cfg(target_os = "windows")]
+    {
+        let _ = app;
+        let _ = window.set_skip_taskbar(enabled);
+    }
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    {
+        let _ = (app, window, enabled);
+    }
+}
+
+/// Let users opt in or out of the Dock-less background presence at runtime.
+#[tauri::command]
+fn set_background_mode(app: AppHandle, window: WebviewWindow, enabled: bool) {
+    apply_background_mode(&app, &window, enabled);
+}
+
+/// Dock the widget to a new anchor and margin, persist the choice, and
+/// re-place the window immediately.
+#[tauri::command]
+fn set_anchor(app: AppHandle, window: WebviewWindow, anchor: Anchor, margin: i32) {
+    {
+        let state = app.state::<Mutex<Config>>();
+        let mut config = state.lock().unwrap();
+        config.anchor = anchor;
+        config.margin = margin;
+        save_config(&app, &config);
+    }
+    reposition(&window);
+}
+
+/// Reveal the widget: reposition, focus, and tell the frontend to reset
+/// its search state.
+fn show_widget(window: &WebviewWindow) {
+    let _ = window.show();
+    reposition(window);
+    let _ = window.set_focus();
+    let _ = window.emit("launcher://toggled", ());
+}
+
+/// Flip the widget between hidden and shown, mirroring Spotlight.
+fn toggle_widget(window: &WebviewWindow) {
+    if window.is_visible().unwrap_or(false) {
+        let _ = window.hide();
+    } else {
+        show_widget(window);
+    }
+}
+
+/// Build the system-tray icon and its context menu. Left-clicking the
+/// icon toggles the widget; the menu offers explicit controls and a way
+/// back when the window is hidden.
+fn build_tray(app: &AppHandle) -> tauri::Result<()> {
+    use tauri::menu::{CheckMenuItem, Menu, MenuItem};
+    use tauri::tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent};
+    use tauri_plugin_autostart::ManagerExt;
+
+    let autostart_enabled = app.autolaunch().is_enabled().unwrap_or(false);
+
+    let show = MenuItem::with_id(app, "show", "Show Launcher", true, None::<&str>)?;
+    let autostart = CheckMenuItem::with_id(
+        app,
+        "autostart",
+        "Toggle Autostart",
+        true,
+        autostart_enabled,
+        None::<&str>,
+    )?;
+    let settings = MenuItem::with_id(app, "settings", "Settings", true, None::<&str>)?;
+    let quit = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
+    let menu = Menu::with_items(app, &[&show, &autostart, &settings, &quit])?;
+
+    let autostart_item = autostart.clone();
+    let mut builder = TrayIconBuilder::with_id("main")
+        .menu(&menu)
+        .show_menu_on_left_click(false)
+        .on_menu_event(move |app, event| match event.id().as_ref() {
+            "show" => {
+                if let Some(window) = app.get_webview_window("main") {
+                    show_widget(&window);
+                }
+            }
+            "autostart" => {
+                let manager = app.autolaunch();
+                let enabled = manager.is_enabled().unwrap_or(false);
+                let _ = if enabled {
+                    manager.disable()
+                } else {
+                    manager.enable()
+                };
+                let _ = autostart_item.set_checked(manager.is_enabled().unwrap_or(!enabled));
+            }
+            "settings" => {
+                if let Some(window) = app.get_webview_window("main") {
+                    show_widget(&window);
+                    let _ = window.emit("launcher://settings", ());
+                }
+            }
+            "quit" => app.exit(0),
+            _ => {}
+        })
+        .on_tray_icon_event(|tray, event| {
+            if let TrayIconEvent::Click {
+                button: MouseButton::Left,
+                button_state: MouseButtonState::Up,
+                ..
+            } = event
+            {
+                if let Some(window) = tray.app_handle().get_webview_window("main") {
+                    toggle_widget(&window);
+                }
+            }
+        });
+
+    if let Some(icon) = app.default_window_icon().cloned() {
+        builder = builder.icon(icon);
+    }
+    builder.build(app)?;
+
+    Ok(())
+}
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
@@ -9,22 +322,62 @@ pub fn run() {
             None::<Vec<&str>>,
         ))
         .plugin(tauri_plugin_window_state::Builder::default().build())
+        .plugin(
+            tauri_plugin_global_shortcut::Builder::new()
+                .with_handler(|app, _shortcut, event| {
+                    if event.state() == tauri_plugin_global_shortcut::ShortcutState::Pressed {
+                        if let Some(window) = app.get_webview_window("main") {
+                            toggle_widget(&window);
+                        }
+                    }
+                })
+                .build(),
+        )
+        .invoke_handler(tauri::generate_handler![
+            set_panel_mode,
+            set_background_mode,
+            set_anchor
+        ])
         .setup(|app| {
+            let config = load_config(app.handle());
+            save_config(app.handle(), &config);
+            app.manage(Mutex::new(config.clone()));
+
+            if let Err(err) = app.global_shortcut().register(config.shortcut.as_str()) {
+                eprintln!("failed to register launcher shortcut {}: {err}", config.shortcut);
+            }
+
             if let Some(window) = app.get_webview_window("main") {
                 let _ = window.set_always_on_top(true);
-                if let Ok(Some(monitor)) = window.current_monitor() {
-                    let monitor_pos = monitor.position();
-                    let monitor_size = monitor.size();
-                    let window_size = window.outer_size().unwrap_or(PhysicalSize::new(210, 56));
-                    let margin = 14_i32;
-
-                    let x = monitor_pos.x + monitor_size.width as i32 - window_size.width as i32 - margin;
-                    let y = monitor_pos.y + monitor_size.height as i32 - window_size.height as i32 - margin;
-                    let _ = window.set_position(Position::Physical(PhysicalPosition::new(x, y)));
-                }
+                apply_panel_mode(&window, true);
+                apply_background_mode(app.handle(), &window, config.background_mode);
+                position_widget(&window, config.anchor, config.margin);
+
+                // Keep the widget docked when the DPI changes or it lands
+                // on a different monitor.
+                let positioned = window.clone();
+                window.on_window_event(move |event| {
+                    if matches!(
+                        event,
+                        WindowEvent::ScaleFactorChanged { .. } | WindowEvent::Moved(_)
+                    ) {
+                        reposition(&positioned);
+                    }
+                });
             }
+
+            build_tray(app.handle())?;
             Ok(())
         })
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app, event| {
+            // Clicking the Dock icon (macOS) reopens the app; bring the
+            // hidden widget back instead of leaving the user stranded.
+            if let tauri::RunEvent::Reopen { .. } = event {
+                if let Some(window) = app.get_webview_window("main") {
+                    show_widget(&window);
+                }
+            }
+        });
 }